@@ -7,6 +7,9 @@ mod instruction_tests;
 #[cfg(test)]
 mod tests;
 
+#[cfg(test)]
+mod verify_tests;
+
 use qsc_data_structures::target::TargetCapabilityFlags;
 use qsc_lowerer::map_hir_package_to_fir;
 use qsc_partial_eval::{partially_evaluate, ProgramEntry};
@@ -45,9 +48,328 @@ pub fn fir_to_qir(
 ) -> Result<String, qsc_partial_eval::Error> {
     let mut program = get_rir_from_compilation(fir_store, compute_properties, entry, capabilities)?;
     check_and_transform(&mut program);
+    // `check_and_transform` should leave the program in a state that satisfies every invariant
+    // `verify` checks. This isn't surfaced as a `qsc_partial_eval::Error` variant (that type is
+    // defined outside this crate), so a debug assertion is the closest approximation: any
+    // violation is a bug in `check_and_transform` or the partial evaluator, not bad user input.
+    debug_assert!(
+        verify(&program).is_ok(),
+        "RIR program violates invariants after check_and_transform: {:?}",
+        verify(&program).err()
+    );
     Ok(ToQir::<String>::to_qir(&program, &program))
 }
 
+/// A violation of one of the structural or type invariants that [`ToQir`]'s QIR lowering in this
+/// module assumes an [`rir::Program`] satisfies.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VerifyError {
+    /// The two operands of a binary instruction don't have the same type.
+    MismatchedOperandTypes {
+        op: String,
+        lhs_ty: String,
+        rhs_ty: String,
+    },
+    /// An instruction's result type doesn't match its operand type.
+    MismatchedResultType {
+        op: String,
+        operand_ty: String,
+        result_ty: String,
+    },
+    /// An integer instruction was given a non-`i64` operand or result.
+    UnsupportedIntegerType { op: String, ty: String },
+    /// A logical instruction (and/or/not) has a non-`i1` result, or a branch condition is a
+    /// non-`i1` operand.
+    UnsupportedLogicalType { op: String, ty: String },
+    /// A comparison instruction doesn't produce an `i1` result.
+    UnsupportedComparisonResultType { op: String, ty: String },
+    /// A `phi` instruction has no incoming values.
+    EmptyPhi { block: rir::BlockId },
+    /// One of a `phi` instruction's incoming values doesn't match the instruction's result type.
+    MismatchedPhiOperandType {
+        block: rir::BlockId,
+        expected: String,
+        found: String,
+    },
+    /// A block doesn't end in exactly one terminator (`br` or `ret`) instruction.
+    MissingTerminator { block: rir::BlockId },
+    /// The callable used as the program's entry point takes one or more inputs.
+    EntryPointHasInput { callable: String },
+}
+
+impl std::fmt::Display for VerifyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MismatchedOperandTypes { op, lhs_ty, rhs_ty } => {
+                write!(f, "mismatched operand types ({lhs_ty}, {rhs_ty}) for {op}")
+            }
+            Self::MismatchedResultType {
+                op,
+                operand_ty,
+                result_ty,
+            } => write!(
+                f,
+                "mismatched input/output types ({operand_ty}, {result_ty}) for {op}"
+            ),
+            Self::UnsupportedIntegerType { op, ty } => {
+                write!(f, "unsupported type {ty} for integer instruction {op}")
+            }
+            Self::UnsupportedLogicalType { op, ty } => {
+                write!(f, "unsupported type {ty} for logical instruction {op}")
+            }
+            Self::UnsupportedComparisonResultType { op, ty } => {
+                write!(f, "unsupported result type {ty} for {op}")
+            }
+            Self::EmptyPhi { block } => write!(f, "phi in {} has no incoming values", block.0),
+            Self::MismatchedPhiOperandType {
+                block,
+                expected,
+                found,
+            } => write!(
+                f,
+                "phi in {} expected type {expected} but found incoming value of type {found}",
+                block.0
+            ),
+            Self::MissingTerminator { block } => {
+                write!(f, "block {} does not end in exactly one terminator", block.0)
+            }
+            Self::EntryPointHasInput { callable } => {
+                write!(f, "entry point callable {callable} should not have an input")
+            }
+        }
+    }
+}
+
+/// Checks that `program` satisfies the structural and type invariants this module's QIR lowering
+/// relies on, returning every violation found rather than stopping at the first one.
+pub fn verify(program: &rir::Program) -> Result<(), Vec<VerifyError>> {
+    let mut errors = Vec::new();
+
+    for (_, callable) in program.callables.iter() {
+        let Some(entry_id) = callable.body else {
+            continue;
+        };
+        if !callable.input_type.is_empty() {
+            errors.push(VerifyError::EntryPointHasInput {
+                callable: callable.name.clone(),
+            });
+        }
+
+        let mut block_ids = vec![entry_id];
+        block_ids.extend(get_all_block_successors(entry_id, program));
+        for block_id in block_ids {
+            verify_block(program, block_id, &mut errors);
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+fn verify_block(program: &rir::Program, block_id: rir::BlockId, errors: &mut Vec<VerifyError>) {
+    let block = program.get_block(block_id);
+    let terminator_count = block.0.iter().filter(|instr| is_terminator(instr)).count();
+    let ends_in_terminator = block.0.last().is_some_and(is_terminator);
+    if terminator_count != 1 || !ends_in_terminator {
+        errors.push(VerifyError::MissingTerminator { block: block_id });
+    }
+
+    for instr in &block.0 {
+        verify_instruction(instr, block_id, errors);
+    }
+}
+
+fn is_terminator(instr: &rir::Instruction) -> bool {
+    matches!(
+        instr,
+        rir::Instruction::Branch(..) | rir::Instruction::Jump(_) | rir::Instruction::Return
+    )
+}
+
+fn verify_instruction(instr: &rir::Instruction, block: rir::BlockId, errors: &mut Vec<VerifyError>) {
+    match instr {
+        rir::Instruction::Add(lhs, rhs, var)
+        | rir::Instruction::Ashr(lhs, rhs, var)
+        | rir::Instruction::BitwiseAnd(lhs, rhs, var)
+        | rir::Instruction::BitwiseOr(lhs, rhs, var)
+        | rir::Instruction::BitwiseXor(lhs, rhs, var)
+        | rir::Instruction::Mul(lhs, rhs, var)
+        | rir::Instruction::Sdiv(lhs, rhs, var)
+        | rir::Instruction::Shl(lhs, rhs, var)
+        | rir::Instruction::Srem(lhs, rhs, var)
+        | rir::Instruction::Sub(lhs, rhs, var) => {
+            verify_binop(instruction_name(instr), lhs, rhs, *var, "i64", errors);
+        }
+        rir::Instruction::LogicalAnd(lhs, rhs, var) | rir::Instruction::LogicalOr(lhs, rhs, var) => {
+            verify_binop(instruction_name(instr), lhs, rhs, *var, "i1", errors);
+        }
+        rir::Instruction::BitwiseNot(value, var) => {
+            verify_unop("not", value, *var, "i64", errors);
+        }
+        rir::Instruction::LogicalNot(value, var) => {
+            verify_unop("not", value, *var, "i1", errors);
+        }
+        rir::Instruction::Icmp(_, lhs, rhs, var) => {
+            let lhs_ty = get_value_ty(lhs);
+            let rhs_ty = get_value_ty(rhs);
+            if lhs_ty != rhs_ty {
+                errors.push(VerifyError::MismatchedOperandTypes {
+                    op: "icmp".to_string(),
+                    lhs_ty: lhs_ty.to_string(),
+                    rhs_ty: rhs_ty.to_string(),
+                });
+            }
+            let var_ty = get_variable_ty(*var);
+            if var_ty != "i1" {
+                errors.push(VerifyError::UnsupportedComparisonResultType {
+                    op: "icmp".to_string(),
+                    ty: var_ty.to_string(),
+                });
+            }
+        }
+        rir::Instruction::Phi(args, var) => {
+            if args.is_empty() {
+                errors.push(VerifyError::EmptyPhi { block });
+            }
+            let var_ty = get_variable_ty(*var);
+            for (arg, _) in args {
+                let arg_ty = get_value_ty(arg);
+                if arg_ty != var_ty {
+                    errors.push(VerifyError::MismatchedPhiOperandType {
+                        block,
+                        expected: var_ty.to_string(),
+                        found: arg_ty.to_string(),
+                    });
+                }
+            }
+        }
+        rir::Instruction::Branch(cond, ..) => {
+            let cond_ty = get_value_ty(cond);
+            if cond_ty != "i1" {
+                errors.push(VerifyError::UnsupportedLogicalType {
+                    op: "br".to_string(),
+                    ty: cond_ty.to_string(),
+                });
+            }
+        }
+        rir::Instruction::Call(..) | rir::Instruction::Jump(_) | rir::Instruction::Return | rir::Instruction::Store(..) => {}
+    }
+}
+
+fn verify_binop(
+    op: &str,
+    lhs: &rir::Operand,
+    rhs: &rir::Operand,
+    variable: rir::Variable,
+    expected_ty: &str,
+    errors: &mut Vec<VerifyError>,
+) {
+    let lhs_ty = get_value_ty(lhs);
+    let rhs_ty = get_value_ty(rhs);
+    let var_ty = get_variable_ty(variable);
+    if lhs_ty != rhs_ty {
+        errors.push(VerifyError::MismatchedOperandTypes {
+            op: op.to_string(),
+            lhs_ty: lhs_ty.to_string(),
+            rhs_ty: rhs_ty.to_string(),
+        });
+    }
+    if lhs_ty != var_ty {
+        errors.push(VerifyError::MismatchedResultType {
+            op: op.to_string(),
+            operand_ty: lhs_ty.to_string(),
+            result_ty: var_ty.to_string(),
+        });
+    }
+    if var_ty != expected_ty {
+        let error = if expected_ty == "i1" {
+            VerifyError::UnsupportedLogicalType {
+                op: op.to_string(),
+                ty: var_ty.to_string(),
+            }
+        } else {
+            VerifyError::UnsupportedIntegerType {
+                op: op.to_string(),
+                ty: var_ty.to_string(),
+            }
+        };
+        errors.push(error);
+    }
+}
+
+fn verify_unop(
+    op: &str,
+    value: &rir::Operand,
+    variable: rir::Variable,
+    expected_ty: &str,
+    errors: &mut Vec<VerifyError>,
+) {
+    let value_ty = get_value_ty(value);
+    let var_ty = get_variable_ty(variable);
+    if value_ty != var_ty {
+        errors.push(VerifyError::MismatchedResultType {
+            op: op.to_string(),
+            operand_ty: value_ty.to_string(),
+            result_ty: var_ty.to_string(),
+        });
+    }
+    if var_ty != expected_ty {
+        let error = if expected_ty == "i1" {
+            VerifyError::UnsupportedLogicalType {
+                op: op.to_string(),
+                ty: var_ty.to_string(),
+            }
+        } else {
+            VerifyError::UnsupportedIntegerType {
+                op: op.to_string(),
+                ty: var_ty.to_string(),
+            }
+        };
+        errors.push(error);
+    }
+}
+
+fn instruction_name(instr: &rir::Instruction) -> &'static str {
+    match instr {
+        rir::Instruction::Add(..) => "add",
+        rir::Instruction::Ashr(..) => "ashr",
+        rir::Instruction::BitwiseAnd(..) => "and",
+        rir::Instruction::BitwiseNot(..) => "not",
+        rir::Instruction::BitwiseOr(..) => "or",
+        rir::Instruction::BitwiseXor(..) => "xor",
+        rir::Instruction::Branch(..) => "br",
+        rir::Instruction::Call(..) => "call",
+        rir::Instruction::LogicalAnd(..) => "and",
+        rir::Instruction::LogicalNot(..) => "not",
+        rir::Instruction::LogicalOr(..) => "or",
+        rir::Instruction::Mul(..) => "mul",
+        rir::Instruction::Icmp(..) => "icmp",
+        rir::Instruction::Jump(..) => "jump",
+        rir::Instruction::Phi(..) => "phi",
+        rir::Instruction::Return => "ret",
+        rir::Instruction::Sdiv(..) => "sdiv",
+        rir::Instruction::Shl(..) => "shl",
+        rir::Instruction::Srem(..) => "srem",
+        rir::Instruction::Store(..) => "store",
+        rir::Instruction::Sub(..) => "sub",
+    }
+}
+
+// BLOCKED (m1c0l/qsharp#chunk2-2): a branch/block simplification pass (merging a block into
+// its unique predecessor when that predecessor's only terminator is an unconditional jump to
+// it, folding a `Branch` on a constant `i1` into a `Jump` and dropping the dead arm, removing
+// blocks left unreachable by those two rewrites, and collapsing single-incoming-edge `Phi`s
+// into a plain copy while fixing up the incoming-block labels of any `Phi` in a merged block's
+// successors) was requested here, invoked from `check_and_transform`. Not implemented: both
+// belong in `qsc_rir::passes`, and that crate's own source isn't part of this snapshot — only
+// the consuming side in this file is. Nothing observed here exposes a mutable block accessor
+// (only the panicking, read-only `get_block`), so there is no API in this crate to write the
+// pass against. Left blocked rather than closed out with a pass built on a guessed-at,
+// unverifiable mutable API.
+
 fn get_rir_from_compilation(
     fir_store: &qsc_fir::fir::PackageStore,
     compute_properties: Option<PackageStoreComputeProperties>,
@@ -153,10 +475,10 @@ impl ToQir<String> for rir::Instruction {
     fn to_qir(&self, program: &rir::Program) -> String {
         match self {
             rir::Instruction::Add(lhs, rhs, variable) => {
-                binop_to_qir("add", lhs, rhs, *variable, program)
+                arithmetic_binop_to_qir("add", "fadd", lhs, rhs, *variable, program)
             }
             rir::Instruction::Ashr(lhs, rhs, variable) => {
-                binop_to_qir("ashr", lhs, rhs, *variable, program)
+                masked_shift_to_qir("ashr", lhs, rhs, *variable, program)
             }
             rir::Instruction::BitwiseAnd(lhs, rhs, variable) => {
                 simple_bitwise_to_qir("and", lhs, rhs, *variable, program)
@@ -191,7 +513,7 @@ impl ToQir<String> for rir::Instruction {
                 logical_binop_to_qir("or", lhs, rhs, *variable, program)
             }
             rir::Instruction::Mul(lhs, rhs, variable) => {
-                binop_to_qir("mul", lhs, rhs, *variable, program)
+                arithmetic_binop_to_qir("mul", "fmul", lhs, rhs, *variable, program)
             }
             rir::Instruction::Icmp(op, lhs, rhs, variable) => {
                 icmp_to_qir(*op, lhs, rhs, *variable, program)
@@ -202,17 +524,17 @@ impl ToQir<String> for rir::Instruction {
             rir::Instruction::Phi(args, variable) => phi_to_qir(args, *variable, program),
             rir::Instruction::Return => "  ret void".to_string(),
             rir::Instruction::Sdiv(lhs, rhs, variable) => {
-                binop_to_qir("sdiv", lhs, rhs, *variable, program)
+                arithmetic_binop_to_qir("sdiv", "fdiv", lhs, rhs, *variable, program)
             }
             rir::Instruction::Shl(lhs, rhs, variable) => {
-                binop_to_qir("shl", lhs, rhs, *variable, program)
+                masked_shift_to_qir("shl", lhs, rhs, *variable, program)
             }
             rir::Instruction::Srem(lhs, rhs, variable) => {
                 binop_to_qir("srem", lhs, rhs, *variable, program)
             }
             rir::Instruction::Store(_, _) => unimplemented!("store should be removed by pass"),
             rir::Instruction::Sub(lhs, rhs, variable) => {
-                binop_to_qir("sub", lhs, rhs, *variable, program)
+                arithmetic_binop_to_qir("sub", "fsub", lhs, rhs, *variable, program)
             }
         }
     }
@@ -330,6 +652,20 @@ fn icmp_to_qir(
     );
 
     assert_eq!(var_ty, "i1", "unsupported output type {var_ty} for icmp");
+
+    // Under the classical_floats capability, comparisons can also compare doubles. LLVM has no
+    // integer-style `icmp` for floating point, so route those through `fcmp` with the ordered
+    // predicate that matches each ConditionCode.
+    if lhs_ty == "double" {
+        return format!(
+            "  {} = fcmp {} {lhs_ty} {}, {}",
+            ToQir::<String>::to_qir(&variable.id, program),
+            ordered_predicate(op),
+            get_value_as_str(lhs, program),
+            get_value_as_str(rhs, program)
+        );
+    }
+
     format!(
         "  {} = icmp {} {lhs_ty} {}, {}",
         ToQir::<String>::to_qir(&variable.id, program),
@@ -339,6 +675,53 @@ fn icmp_to_qir(
     )
 }
 
+/// The LLVM ordered `fcmp` predicate corresponding to a `ConditionCode`'s integer predicate.
+fn ordered_predicate(op: ConditionCode) -> &'static str {
+    match op {
+        ConditionCode::Eq => "oeq",
+        ConditionCode::Ne => "one",
+        ConditionCode::Sgt => "ogt",
+        ConditionCode::Sge => "oge",
+        ConditionCode::Slt => "olt",
+        ConditionCode::Sle => "ole",
+    }
+}
+
+/// Lowers `Add`/`Sub`/`Mul`/`Sdiv`, which support both integer and (under the `classical_floats`
+/// capability) floating-point operands; `int_op` and `float_op` are the LLVM opcodes for each.
+fn arithmetic_binop_to_qir(
+    int_op: &str,
+    float_op: &str,
+    lhs: &rir::Operand,
+    rhs: &rir::Operand,
+    variable: rir::Variable,
+    program: &rir::Program,
+) -> String {
+    let lhs_ty = get_value_ty(lhs);
+    let rhs_ty = get_value_ty(rhs);
+    let var_ty = get_variable_ty(variable);
+    assert_eq!(
+        lhs_ty, rhs_ty,
+        "mismatched input types ({lhs_ty}, {rhs_ty}) for {int_op}"
+    );
+    assert_eq!(
+        lhs_ty, var_ty,
+        "mismatched input/output types ({lhs_ty}, {var_ty}) for {int_op}"
+    );
+    let op = match var_ty {
+        "i64" => int_op,
+        "double" => float_op,
+        _ => panic!("unsupported type {var_ty} for {int_op}"),
+    };
+
+    format!(
+        "  {} = {op} {var_ty} {}, {}",
+        ToQir::<String>::to_qir(&variable.id, program),
+        get_value_as_str(lhs, program),
+        get_value_as_str(rhs, program)
+    )
+}
+
 fn binop_to_qir(
     op: &str,
     lhs: &rir::Operand,
@@ -367,6 +750,38 @@ fn binop_to_qir(
     )
 }
 
+fn masked_shift_to_qir(
+    op: &str,
+    lhs: &rir::Operand,
+    rhs: &rir::Operand,
+    variable: rir::Variable,
+    program: &rir::Program,
+) -> String {
+    let lhs_ty = get_value_ty(lhs);
+    let rhs_ty = get_value_ty(rhs);
+    let var_ty = get_variable_ty(variable);
+    assert_eq!(
+        lhs_ty, rhs_ty,
+        "mismatched input types ({lhs_ty}, {rhs_ty}) for {op}"
+    );
+    assert_eq!(
+        lhs_ty, var_ty,
+        "mismatched input/output types ({lhs_ty}, {var_ty}) for {op}"
+    );
+    assert_eq!(var_ty, "i64", "unsupported type {var_ty} for {op}");
+
+    // LLVM gives `shl`/`ashr` poison semantics when the shift amount is >= the operand's bit
+    // width, so mask it into range first. The result variable's id is unique within the function,
+    // so suffixing it names this temporary without colliding with any other variable.
+    let masked_shamt = format!("%var_{}_shamt", variable.id.0);
+    format!(
+        "  {masked_shamt} = and i64 {}, 63\n  {} = {op} {var_ty} {}, {masked_shamt}",
+        get_value_as_str(rhs, program),
+        ToQir::<String>::to_qir(&variable.id, program),
+        get_value_as_str(lhs, program),
+    )
+}
+
 fn simple_bitwise_to_qir(
     op: &str,
     lhs: &rir::Operand,
@@ -455,7 +870,7 @@ fn get_value_ty(lhs: &rir::Operand) -> &str {
         rir::Operand::Literal(lit) => match lit {
             rir::Literal::Integer(_) => "i64",
             rir::Literal::Bool(_) => "i1",
-            rir::Literal::Double(_) => "f64",
+            rir::Literal::Double(_) => "double",
             rir::Literal::Qubit(_) => "%Qubit*",
             rir::Literal::Result(_) => "%Result*",
             rir::Literal::Pointer => "i8*",
@@ -468,7 +883,7 @@ fn get_variable_ty(variable: rir::Variable) -> &'static str {
     match variable.ty {
         rir::Ty::Integer => "i64",
         rir::Ty::Boolean => "i1",
-        rir::Ty::Double => "f64",
+        rir::Ty::Double => "double",
         rir::Ty::Qubit => "%Qubit*",
         rir::Ty::Result => "%Result*",
         rir::Ty::Pointer => "i8*",
@@ -531,6 +946,76 @@ impl ToQir<String> for rir::Callable {
     }
 }
 
+/// Prints `program` in a plain textual RIR format (distinct from the LLVM IR `ToQir` produces),
+/// intended for caching an already-lowered program and for inspecting it in tooling/tests without
+/// going through LLVM syntax.
+///
+/// This only covers the printer half of the request: round-tripping the text back into an
+/// `rir::Program`, and `serde::Serialize`/`Deserialize` derives on the RIR types themselves, both
+/// need to either construct a `rir::Program` from scratch or edit the RIR type definitions -
+/// neither of which this crate has a way to do, since `rir::Program` exposes no public
+/// constructor or builder here (only the accessors `qir.rs` already uses) and the type
+/// definitions live in a crate not present in this snapshot.
+pub fn to_rir_text(program: &rir::Program) -> String {
+    let mut out = String::new();
+    out.push_str(&format!(
+        "profile: {}\n",
+        if program.config.is_base() {
+            "base"
+        } else {
+            "adaptive"
+        }
+    ));
+    out.push_str(&format!("num_qubits: {}\n", program.num_qubits));
+    out.push_str(&format!("num_results: {}\n\n", program.num_results));
+
+    for (id, callable) in program.callables.iter() {
+        out.push_str(&callable_to_rir_text(id, callable, program));
+        out.push('\n');
+    }
+
+    out
+}
+
+fn callable_to_rir_text(id: rir::CallableId, callable: &rir::Callable, program: &rir::Program) -> String {
+    let input_type = callable
+        .input_type
+        .iter()
+        .map(|t| ToQir::<String>::to_qir(t, program))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let output_type = ToQir::<String>::to_qir(&callable.output_type, program);
+
+    let Some(entry_id) = callable.body else {
+        return format!(
+            "callable {}(@{}) ({input_type}) -> {output_type} (declare)",
+            callable.name, id.0
+        );
+    };
+
+    let mut body = String::new();
+    let mut block_ids = vec![entry_id];
+    block_ids.extend(get_all_block_successors(entry_id, program));
+    for block_id in block_ids {
+        let block = program.get_block(block_id);
+        body.push_str(&format!(
+            "  {}:\n",
+            ToQir::<String>::to_qir(&block_id, program)
+        ));
+        for instr in &block.0 {
+            body.push_str(&format!(
+                "    {}\n",
+                ToQir::<String>::to_qir(instr, program)
+            ));
+        }
+    }
+
+    format!(
+        "callable {}(@{}) ({input_type}) -> {output_type} {{\n{body}}}",
+        callable.name, id.0
+    )
+}
+
 impl ToQir<String> for rir::Program {
     fn to_qir(&self, _program: &rir::Program) -> String {
         let callables = self
@@ -553,6 +1038,257 @@ impl ToQir<String> for rir::Program {
     }
 }
 
+/// The result of [`analyze_rir`]: which branch conditions could be proven constant, which blocks
+/// are consequently unreachable, and the highest qubit/result id actually used. These ids are not
+/// checked against the program's declared `num_qubits`/`num_results`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct RirAnalysis {
+    /// Branches whose condition was proven constant, and which way they always go.
+    pub folded_conditions: Vec<(rir::BlockId, bool)>,
+    /// Blocks that the symbolic walk never reaches, e.g. because a predecessor's branch was
+    /// folded away from them.
+    pub unreachable_blocks: Vec<rir::BlockId>,
+    /// The highest qubit id referenced by a literal operand anywhere in the program, if any.
+    pub max_qubit_id: Option<u64>,
+    /// The highest result id referenced by a literal operand anywhere in the program, if any.
+    pub max_result_id: Option<u64>,
+    /// Set if the analysis stopped early because it exhausted its step budget, meaning the other
+    /// fields may be incomplete for this program.
+    pub step_limit_reached: bool,
+}
+
+/// Symbolic values the analysis below can track through straight-line code. Anything it can't
+/// reason about (inputs, call results, merged values with no single constant) is `Unknown`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum SymValue {
+    Unknown,
+    Bool(bool),
+    Integer(i64),
+}
+
+type SymEnv = std::collections::HashMap<rir::VariableId, SymValue>;
+
+fn eval_operand(env: &SymEnv, operand: &rir::Operand) -> SymValue {
+    match operand {
+        rir::Operand::Literal(rir::Literal::Bool(b)) => SymValue::Bool(*b),
+        rir::Operand::Literal(rir::Literal::Integer(i)) => SymValue::Integer(*i),
+        rir::Operand::Literal(_) => SymValue::Unknown,
+        rir::Operand::Variable(var) => env.get(&var.id).copied().unwrap_or(SymValue::Unknown),
+    }
+}
+
+/// Symbolically walks every reachable block of every callable with a body, folding constant
+/// branch conditions and reporting which blocks become unreachable as a result, and separately
+/// scans every operand for the highest qubit/result id used. `step_limit` bounds the number of
+/// blocks the worklist will process per callable, guarding against unbounded work on a CFG with
+/// many paths (e.g. from unrolled loops).
+pub fn analyze_rir(program: &rir::Program, step_limit: usize) -> RirAnalysis {
+    let mut analysis = RirAnalysis::default();
+
+    for (_, callable) in program.callables.iter() {
+        scan_resource_bounds(callable, program, &mut analysis);
+
+        let Some(entry_id) = callable.body else {
+            continue;
+        };
+        let all_blocks: Vec<_> = std::iter::once(entry_id)
+            .chain(get_all_block_successors(entry_id, program))
+            .collect();
+
+        let mut reachable = std::collections::HashSet::new();
+        let mut worklist = std::collections::VecDeque::new();
+        worklist.push_back((entry_id, SymEnv::new()));
+        let mut steps = 0usize;
+
+        while let Some((block_id, mut env)) = worklist.pop_front() {
+            if steps >= step_limit {
+                analysis.step_limit_reached = true;
+                break;
+            }
+            steps += 1;
+            reachable.insert(block_id);
+
+            let block = program.get_block(block_id);
+            let mut successors = Vec::new();
+            for instr in &block.0 {
+                match instr {
+                    rir::Instruction::Add(lhs, rhs, var) => {
+                        fold_int(&mut env, lhs, rhs, *var, i64::wrapping_add);
+                    }
+                    rir::Instruction::Sub(lhs, rhs, var) => {
+                        fold_int(&mut env, lhs, rhs, *var, i64::wrapping_sub);
+                    }
+                    rir::Instruction::Mul(lhs, rhs, var) => {
+                        fold_int(&mut env, lhs, rhs, *var, i64::wrapping_mul);
+                    }
+                    rir::Instruction::LogicalAnd(lhs, rhs, var) => {
+                        fold_bool(&mut env, lhs, rhs, *var, |a, b| a && b);
+                    }
+                    rir::Instruction::LogicalOr(lhs, rhs, var) => {
+                        fold_bool(&mut env, lhs, rhs, *var, |a, b| a || b);
+                    }
+                    rir::Instruction::LogicalNot(value, var) => {
+                        env.insert(
+                            var.id,
+                            match eval_operand(&env, value) {
+                                SymValue::Bool(b) => SymValue::Bool(!b),
+                                _ => SymValue::Unknown,
+                            },
+                        );
+                    }
+                    rir::Instruction::Icmp(op, lhs, rhs, var) => {
+                        let value = match (eval_operand(&env, lhs), eval_operand(&env, rhs)) {
+                            (SymValue::Integer(l), SymValue::Integer(r)) => {
+                                SymValue::Bool(eval_condition(*op, l, r))
+                            }
+                            _ => SymValue::Unknown,
+                        };
+                        env.insert(var.id, value);
+                    }
+                    rir::Instruction::Branch(cond, true_id, false_id) => {
+                        match eval_operand(&env, cond) {
+                            SymValue::Bool(b) => {
+                                analysis.folded_conditions.push((block_id, b));
+                                successors.push(if b { *true_id } else { *false_id });
+                            }
+                            _ => {
+                                successors.push(*true_id);
+                                successors.push(*false_id);
+                            }
+                        }
+                    }
+                    rir::Instruction::Jump(target) => successors.push(*target),
+                    rir::Instruction::Phi(_, var) => {
+                        // A value merged from more than one predecessor isn't something this
+                        // straight-line-only analysis tracks; treat it conservatively.
+                        env.insert(var.id, SymValue::Unknown);
+                    }
+                    rir::Instruction::Call(_, _, Some(var)) => {
+                        env.insert(var.id, SymValue::Unknown);
+                    }
+                    rir::Instruction::BitwiseAnd(..)
+                    | rir::Instruction::BitwiseOr(..)
+                    | rir::Instruction::BitwiseXor(..)
+                    | rir::Instruction::BitwiseNot(..)
+                    | rir::Instruction::Ashr(..)
+                    | rir::Instruction::Shl(..)
+                    | rir::Instruction::Sdiv(..)
+                    | rir::Instruction::Srem(..) => {
+                        // Not folded today; the variable they define simply stays unknown to any
+                        // later instruction that reads it (the default for a missing map entry).
+                    }
+                    rir::Instruction::Call(_, _, None) | rir::Instruction::Return | rir::Instruction::Store(..) => {}
+                }
+            }
+
+            for successor in successors {
+                worklist.push_back((successor, env.clone()));
+            }
+        }
+
+        for block_id in all_blocks {
+            if !reachable.contains(&block_id) {
+                analysis.unreachable_blocks.push(block_id);
+            }
+        }
+    }
+
+    analysis
+}
+
+fn fold_int(
+    env: &mut SymEnv,
+    lhs: &rir::Operand,
+    rhs: &rir::Operand,
+    var: rir::Variable,
+    f: impl Fn(i64, i64) -> i64,
+) {
+    let value = match (eval_operand(env, lhs), eval_operand(env, rhs)) {
+        (SymValue::Integer(l), SymValue::Integer(r)) => SymValue::Integer(f(l, r)),
+        _ => SymValue::Unknown,
+    };
+    env.insert(var.id, value);
+}
+
+fn fold_bool(
+    env: &mut SymEnv,
+    lhs: &rir::Operand,
+    rhs: &rir::Operand,
+    var: rir::Variable,
+    f: impl Fn(bool, bool) -> bool,
+) {
+    let value = match (eval_operand(env, lhs), eval_operand(env, rhs)) {
+        (SymValue::Bool(l), SymValue::Bool(r)) => SymValue::Bool(f(l, r)),
+        _ => SymValue::Unknown,
+    };
+    env.insert(var.id, value);
+}
+
+fn eval_condition(op: ConditionCode, lhs: i64, rhs: i64) -> bool {
+    match op {
+        ConditionCode::Eq => lhs == rhs,
+        ConditionCode::Ne => lhs != rhs,
+        ConditionCode::Sgt => lhs > rhs,
+        ConditionCode::Sge => lhs >= rhs,
+        ConditionCode::Slt => lhs < rhs,
+        ConditionCode::Sle => lhs <= rhs,
+    }
+}
+
+fn scan_resource_bounds(callable: &rir::Callable, program: &rir::Program, analysis: &mut RirAnalysis) {
+    let Some(entry_id) = callable.body else {
+        return;
+    };
+    let mut block_ids = vec![entry_id];
+    block_ids.extend(get_all_block_successors(entry_id, program));
+    for block_id in block_ids {
+        for instr in &program.get_block(block_id).0 {
+            for operand in instruction_operands(instr) {
+                match operand {
+                    rir::Operand::Literal(rir::Literal::Qubit(q)) => {
+                        let q = u64::from(*q);
+                        analysis.max_qubit_id = Some(analysis.max_qubit_id.map_or(q, |m| m.max(q)));
+                    }
+                    rir::Operand::Literal(rir::Literal::Result(r)) => {
+                        let r = u64::from(*r);
+                        analysis.max_result_id = Some(analysis.max_result_id.map_or(r, |m| m.max(r)));
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+/// Every operand an instruction reads, for analyses (like resource-bound scanning) that don't
+/// care which instruction it came from.
+fn instruction_operands(instr: &rir::Instruction) -> Vec<&rir::Operand> {
+    match instr {
+        rir::Instruction::Add(lhs, rhs, _)
+        | rir::Instruction::Ashr(lhs, rhs, _)
+        | rir::Instruction::BitwiseAnd(lhs, rhs, _)
+        | rir::Instruction::BitwiseOr(lhs, rhs, _)
+        | rir::Instruction::BitwiseXor(lhs, rhs, _)
+        | rir::Instruction::LogicalAnd(lhs, rhs, _)
+        | rir::Instruction::LogicalOr(lhs, rhs, _)
+        | rir::Instruction::Mul(lhs, rhs, _)
+        | rir::Instruction::Icmp(_, lhs, rhs, _)
+        | rir::Instruction::Sdiv(lhs, rhs, _)
+        | rir::Instruction::Shl(lhs, rhs, _)
+        | rir::Instruction::Srem(lhs, rhs, _)
+        | rir::Instruction::Sub(lhs, rhs, _) => vec![lhs, rhs],
+        rir::Instruction::BitwiseNot(value, _) | rir::Instruction::LogicalNot(value, _) => {
+            vec![value]
+        }
+        rir::Instruction::Branch(cond, ..) => vec![cond],
+        rir::Instruction::Call(_, args, _) => args.iter().collect(),
+        rir::Instruction::Phi(args, _) => args.iter().map(|(value, _)| value).collect(),
+        rir::Instruction::Jump(_) | rir::Instruction::Return | rir::Instruction::Store(..) => {
+            Vec::new()
+        }
+    }
+}
+
 /// Create the module metadata for the given program.
 /// creating the `llvm.module.flags` and its associated values.
 fn get_module_metadata(program: &rir::Program) -> String {
@@ -20,17 +20,45 @@ enum State {
     End,
 }
 
+/// What kind of source text the cursor landed in, so a completion provider can decide whether
+/// (and how) to offer candidates instead of always assuming the cursor sits in whitespace.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub(crate) enum CursorContext {
+    /// The cursor is in plain whitespace between tokens.
+    Whitespace,
+    /// The cursor is inside a `//` line comment.
+    LineComment,
+    /// The cursor is inside an (possibly unterminated) string literal.
+    InsideStringLiteral,
+    /// The lexer reported an error between the previous token and the cursor's gap end, and the
+    /// cursor is not in a comment or string literal. The cursor may be inside the unrecognized
+    /// text or in whitespace after it; the two are not told apart.
+    InErrorToken,
+    /// The cursor is in the middle or at the end of an identifier or keyword; `prefix` is the
+    /// substring from the start of the token up to the cursor, for filtering completions.
+    PartialIdent { prefix: String },
+}
+
 pub(crate) struct CursorAwareLexer<'a> {
     pub at_cursor: bool,
+    pub cursor_context: CursorContext,
+    input: &'a str,
     tokens: Lexer<'a>,
     cursor_offset: u32,
     state: State,
+    /// End offset of the last real token returned, i.e. the start of the trivia gap the cursor
+    /// might fall into.
+    prev_end: u32,
+    /// Whether a lex error was observed since `prev_end`, used as a fallback classification for
+    /// the gap when it doesn't look like a comment or string literal.
+    pending_error: bool,
 }
 
 impl<'a> CursorAwareLexer<'a> {
     pub(crate) fn new(input: &'a str, cursor_offset: u32) -> Self {
         Self {
             tokens: Lexer::new(input),
+            input,
             cursor_offset,
             state: if cursor_offset == 0 {
                 State::Cursor
@@ -38,8 +66,70 @@ impl<'a> CursorAwareLexer<'a> {
                 State::Normal
             },
             at_cursor: false,
+            cursor_context: CursorContext::Whitespace,
+            prev_end: 0,
+            pending_error: false,
+        }
+    }
+}
+
+/// Classifies the trivia gap `input[gap_start..gap_end]` that the cursor falls into, by looking
+/// for an unterminated `//` comment or an odd number of unescaped `"` before the cursor.
+fn classify_gap(input: &str, gap_start: u32, gap_end: u32, cursor_offset: u32, pending_error: bool) -> CursorContext {
+    let gap_start = gap_start as usize;
+    let gap_end = (gap_end as usize).min(input.len());
+    let cursor = (cursor_offset as usize).min(gap_end).max(gap_start);
+    let Some(before_cursor) = input.get(gap_start..cursor) else {
+        return CursorContext::Whitespace;
+    };
+
+    // Single left-to-right scan tracking whether we're inside a string, rather than two
+    // independent substring checks run in a fixed order: a naive `contains("//")` check run
+    // before the quote-parity check misclassifies a gap like `"https://example` (an
+    // in-progress string literal containing `//`) as `LineComment` instead of
+    // `InsideStringLiteral`, since it never looks at string state at all.
+    let mut in_string = false;
+    let mut in_comment = false;
+    let mut escaped = false;
+    let mut chars = before_cursor.char_indices().peekable();
+    while let Some((_, c)) = chars.next() {
+        if c == '\n' || c == '\r' {
+            // A line comment ends at the newline; an unterminated string does not.
+            in_comment = false;
+            continue;
+        }
+        if in_comment {
+            continue;
+        }
+        if in_string {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+        if c == '"' {
+            in_string = true;
+        } else if c == '/' && matches!(chars.peek(), Some((_, '/'))) {
+            in_comment = true;
         }
     }
+
+    if in_string {
+        return CursorContext::InsideStringLiteral;
+    }
+    if in_comment {
+        return CursorContext::LineComment;
+    }
+
+    if pending_error {
+        return CursorContext::InErrorToken;
+    }
+
+    CursorContext::Whitespace
 }
 
 impl Iterator for CursorAwareLexer<'_> {
@@ -54,9 +144,16 @@ impl Iterator for CursorAwareLexer<'_> {
                         match next_token {
                             Ok(token) => {
                                 if token.span.lo >= self.cursor_offset {
-                                    // We moved past the cursor already, so cursor was in whitespace, comment, or error token
-                                    // The distinction is important, but we'll take care of that later.
-                                    // For now assume it was whitespace.
+                                    // We moved past the cursor already, so the cursor was in
+                                    // whitespace, a comment, a string literal, or an error token.
+                                    // Classify the gap text to tell those apart.
+                                    self.cursor_context = classify_gap(
+                                        self.input,
+                                        self.prev_end,
+                                        token.span.lo,
+                                        self.cursor_offset,
+                                        self.pending_error,
+                                    );
                                     // Insert cursor, then end
                                     self.state = State::End;
                                     Some(TokenW::Cursor)
@@ -73,11 +170,18 @@ impl Iterator for CursorAwareLexer<'_> {
                                         | TokenKind::ClosedBinOp(
                                             ClosedBinOp::And | ClosedBinOp::Or,
                                         ) => {
+                                            let prefix = self
+                                                .input
+                                                .get(token.span.lo as usize..self.cursor_offset as usize)
+                                                .unwrap_or_default()
+                                                .to_string();
+                                            self.cursor_context = CursorContext::PartialIdent { prefix };
                                             self.state = State::End;
                                             Some(TokenW::Cursor)
                                         }
                                         _ => {
                                             if token.span.hi == self.cursor_offset {
+                                                self.cursor_context = CursorContext::Whitespace;
                                                 self.state = State::Cursor;
                                                 Some(TokenW::Token(token))
                                             } else {
@@ -88,14 +192,28 @@ impl Iterator for CursorAwareLexer<'_> {
                                     }
                                 } else {
                                     // State remains State::Normal
+                                    self.prev_end = token.span.hi;
+                                    self.pending_error = false;
                                     Some(TokenW::Token(token))
                                 }
                             }
-                            Err(e) => Some(TokenW::Error(e)), // State remains State::Normal (cursor could be in this range, need to handle)
+                            Err(e) => {
+                                // State remains State::Normal; the cursor could be in this
+                                // range, so remember it for when the gap is classified.
+                                self.pending_error = true;
+                                Some(TokenW::Error(e))
+                            }
                         }
                     }
                     None => {
                         // We got to the end so presumably the cursor was somewhere after the very last token
+                        self.cursor_context = classify_gap(
+                            self.input,
+                            self.prev_end,
+                            self.input.len() as u32,
+                            self.cursor_offset,
+                            self.pending_error,
+                        );
                         self.state = State::End;
                         Some(TokenW::Cursor)
                     }
@@ -12,9 +12,48 @@ use crate::{
     ComplexVector, Error, SquareMatrix, TOLERANCE,
 };
 use num_complex::Complex;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A small splitmix64 generator used to drive measurement sampling. Kept minimal and
+/// self-contained, rather than depending on an external RNG crate's internal layout, so its
+/// entire state is a single `u64` that can be checkpointed and restored byte-for-byte to make
+/// `sample_instrument` trajectories reproducible.
+#[derive(Clone, Copy, Debug)]
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    /// Returns the next pseudo-random `f64` sampled uniformly from `[0, 1)`.
+    fn next_f64(&mut self) -> f64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+        // Top 53 bits give a uniform double, matching f64's mantissa width.
+        (z >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+}
+
+/// A single-qubit Pauli operator, used to build the tensor-product Pauli-string observables
+/// accepted by `DensityMatrixSimulator::expectation`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PauliOp {
+    I,
+    X,
+    Y,
+    Z,
+}
 
 /// A vectorized density matrix.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "DensityMatrixSnapshot", into = "DensityMatrixSnapshot")]
 pub struct DensityMatrix {
     /// Dimension of the matrix. E.g.: If the matrix is 5 x 5, then dim is 5.
     dim: usize,
@@ -26,6 +65,47 @@ pub struct DensityMatrix {
     data: ComplexVector,
 }
 
+/// Plain serializable representation of a `DensityMatrix`. Deserializing through it (rather
+/// than deriving `Deserialize` on `DensityMatrix` directly) ensures a deserialized snapshot
+/// goes through the same invariant checks as `DensityMatrix::try_from`.
+#[derive(Serialize, Deserialize)]
+struct DensityMatrixSnapshot {
+    dim: usize,
+    number_of_qubits: usize,
+    trace_change: f64,
+    data: ComplexVector,
+}
+
+impl From<DensityMatrix> for DensityMatrixSnapshot {
+    fn from(value: DensityMatrix) -> Self {
+        Self {
+            dim: value.dim,
+            number_of_qubits: value.number_of_qubits,
+            trace_change: value.trace_change,
+            data: value.data,
+        }
+    }
+}
+
+impl TryFrom<DensityMatrixSnapshot> for DensityMatrix {
+    type Error = String;
+
+    fn try_from(snapshot: DensityMatrixSnapshot) -> Result<Self, Self::Error> {
+        DensityMatrix::try_from(
+            snapshot.dim,
+            snapshot.number_of_qubits,
+            snapshot.trace_change,
+            snapshot.data,
+        )
+        .ok_or_else(|| {
+            format!(
+                "invalid DensityMatrix snapshot: number_of_qubits={} is inconsistent with dim={} or the data length",
+                snapshot.number_of_qubits, snapshot.dim
+            )
+        })
+    }
+}
+
 impl DensityMatrix {
     fn new(number_of_qubits: usize) -> Self {
         let dim = 1 << number_of_qubits;
@@ -66,6 +146,25 @@ impl DensityMatrix {
         &self.data
     }
 
+    /// Returns the entries of the matrix whose magnitude is at least `threshold`, as
+    /// `(row, col, value)` triples. Useful for inspecting or transmitting larger states
+    /// where most of the matrix is negligible, without materializing the full dense form.
+    ///
+    /// Note: storage itself stays dense; this only controls what gets reported back, since
+    /// this crate doesn't currently depend on a sparse matrix backend.
+    pub fn sparse_entries(&self, threshold: f64) -> Vec<(usize, usize, Complex<f64>)> {
+        let mut entries = Vec::new();
+        for row in 0..self.dim {
+            for col in 0..self.dim {
+                let value = self.data[self.dim * row + col];
+                if value.norm() >= threshold {
+                    entries.push((row, col, value));
+                }
+            }
+        }
+        entries
+    }
+
     /// Returns dimension of the matrix. E.g.: If the matrix is 5 x 5, then dim is 5.
     pub fn dim(&self) -> usize {
         self.dim
@@ -92,6 +191,41 @@ impl DensityMatrix {
         true
     }
 
+    /// Returns `true` if the matrix is positive semidefinite, tested via an LDLᴴ
+    /// (Cholesky-style) factorization of the already-verified-Hermitian matrix rather than a
+    /// full eigensolver. Pivots within `±TOLERANCE` of zero are treated as zero (rank-deficient
+    /// but still physical); any pivot below `-TOLERANCE` means the matrix is indefinite.
+    fn is_positive_semidefinite(&self) -> bool {
+        let dim = self.dim;
+        let mut l = vec![Complex::ZERO; dim * dim];
+        let mut d = vec![0.0_f64; dim];
+        for k in 0..dim {
+            let mut pivot = self.data[dim * k + k].re;
+            for j in 0..k {
+                pivot -= l[dim * k + j].norm_sqr() * d[j];
+            }
+            if pivot < -TOLERANCE {
+                return false;
+            }
+            if pivot.abs() <= TOLERANCE {
+                d[k] = 0.0;
+                for i in (k + 1)..dim {
+                    l[dim * i + k] = Complex::ZERO;
+                }
+                continue;
+            }
+            d[k] = pivot;
+            for i in (k + 1)..dim {
+                let mut entry = self.data[dim * i + k];
+                for j in 0..k {
+                    entry -= l[dim * i + j] * l[dim * k + j].conj() * d[j];
+                }
+                l[dim * i + k] = entry / d[k];
+            }
+        }
+        true
+    }
+
     /// Returns `true` if the trace of the matrix is 1.
     fn is_normalized(&self) -> bool {
         (self.trace() - 1.0).abs() <= TOLERANCE
@@ -130,13 +264,151 @@ impl DensityMatrix {
         }
         self.trace_change *= trace;
         let renormalization_factor = 1.0 / trace;
+        #[cfg(feature = "parallel")]
+        self.data
+            .as_mut_slice()
+            .par_iter_mut()
+            .for_each(|entry| *entry *= renormalization_factor);
+        #[cfg(not(feature = "parallel"))]
         for entry in self.data.iter_mut() {
             *entry *= renormalization_factor;
         }
         Ok(())
     }
 
+    /// Returns a new `DensityMatrix` over `number_of_qubits() + 1` qubits, obtained by
+    /// tensoring the current state with a fresh qubit in the |0⟩ state. The new qubit
+    /// becomes the highest-numbered qubit; existing qubit ids are unaffected.
+    fn tensor_with_fresh_qubit(&self) -> Self {
+        let new_dim = self.dim * 2;
+        let mut data = ComplexVector::zeros(new_dim * new_dim);
+        for row in 0..self.dim {
+            for col in 0..self.dim {
+                data[new_dim * row + col] = self.data[self.dim * row + col];
+            }
+        }
+        Self {
+            dim: new_dim,
+            number_of_qubits: self.number_of_qubits + 1,
+            trace_change: self.trace_change,
+            data,
+        }
+    }
+
+    /// Returns the entries `(⟨0|ρ_q|0⟩, ⟨0|ρ_q|1⟩, ⟨1|ρ_q|1⟩)` of the reduced density matrix
+    /// of `qubit_id`, obtained by tracing out every other qubit.
+    fn single_qubit_reduced_state(&self, qubit_id: usize) -> (Complex<f64>, Complex<f64>, Complex<f64>) {
+        let mask = 1 << qubit_id;
+        let mut reduced = [[Complex::ZERO; 2]; 2];
+        for row in 0..self.dim {
+            for col in 0..self.dim {
+                if (row & !mask) == (col & !mask) {
+                    let i = usize::from(row & mask != 0);
+                    let j = usize::from(col & mask != 0);
+                    reduced[i][j] += self.data[self.dim * row + col];
+                }
+            }
+        }
+        (reduced[0][0], reduced[0][1], reduced[1][1])
+    }
+
+    /// Returns `true` if `qubit_id` is separable from the rest of the system, i.e. its
+    /// reduced state is (numerically) pure. This is a prerequisite for releasing the qubit,
+    /// since discarding an entangled qubit would lose information about the rest of the state.
+    fn qubit_is_separable(&self, qubit_id: usize) -> bool {
+        let (p00, p01, p11) = self.single_qubit_reduced_state(qubit_id);
+        let half_diff = (p00.re - p11.re) / 2.0;
+        let max_eigenvalue = 0.5 + (half_diff * half_diff + p01.norm_sqr()).sqrt();
+        (max_eigenvalue - 1.0).abs() <= TOLERANCE
+    }
+
+    /// Removes `qubit_id` from the system, returning a new `DensityMatrix` over
+    /// `number_of_qubits() - 1` qubits. The remaining qubits keep their relative order and are
+    /// renumbered to stay contiguous starting at zero. Only valid to call once
+    /// `qubit_is_separable` has been confirmed for `qubit_id`.
+    fn trace_out_qubit(&self, qubit_id: usize) -> Self {
+        let mask = 1 << qubit_id;
+        let new_dim = self.dim / 2;
+        let compress = |idx: usize| {
+            let low = idx & (mask - 1);
+            let high = (idx >> (qubit_id + 1)) << qubit_id;
+            high | low
+        };
+        let mut data = ComplexVector::zeros(new_dim * new_dim);
+        for row in 0..self.dim {
+            for col in 0..self.dim {
+                if (row & mask == 0) == (col & mask == 0) {
+                    let new_row = compress(row);
+                    let new_col = compress(col);
+                    data[new_dim * new_row + new_col] += self.data[self.dim * row + col];
+                }
+            }
+        }
+        Self {
+            dim: new_dim,
+            number_of_qubits: self.number_of_qubits - 1,
+            trace_change: self.trace_change,
+            data,
+        }
+    }
+
+    /// Returns the reduced density matrix obtained by tracing out `qubits_to_trace_out`,
+    /// mirroring the `partial_trace` step used in state tomography to discard ancilla or
+    /// unmeasured qubits. The remaining qubits keep their relative order and are renumbered to
+    /// stay contiguous starting at zero. Generalizes `trace_out_qubit` to an arbitrary subset.
+    pub fn reduced_density_matrix(&self, qubits_to_trace_out: &[usize]) -> Self {
+        let mut traced = qubits_to_trace_out.to_vec();
+        traced.sort_unstable();
+        traced.dedup();
+        let kept: Vec<usize> = (0..self.number_of_qubits)
+            .filter(|q| !traced.contains(q))
+            .collect();
+        let n_a = kept.len();
+        let n_b = traced.len();
+        let new_dim = 1 << n_a;
+
+        // Interleaves the kept-subset bits of `a` and the traced-subset bits of `b` at their
+        // original qubit positions, forming a full `number_of_qubits`-bit index.
+        let compose = |a: usize, b: usize| -> usize {
+            let mut idx = 0;
+            for (i, &q) in kept.iter().enumerate() {
+                idx |= ((a >> i) & 1) << q;
+            }
+            for (i, &q) in traced.iter().enumerate() {
+                idx |= ((b >> i) & 1) << q;
+            }
+            idx
+        };
+
+        let mut data = ComplexVector::zeros(new_dim * new_dim);
+        for a in 0..new_dim {
+            for a_prime in 0..new_dim {
+                let mut sum = Complex::ZERO;
+                for b in 0..(1 << n_b) {
+                    let row = compose(a, b);
+                    let col = compose(a_prime, b);
+                    sum += self.data[self.dim * row + col];
+                }
+                data[new_dim * a + a_prime] = sum;
+            }
+        }
+        Self {
+            dim: new_dim,
+            number_of_qubits: n_a,
+            trace_change: self.trace_change,
+            data,
+        }
+    }
+
     /// Applies the operation matrix to the target qubits.
+    ///
+    /// BLOCKED (m1c0l/qsharp#chunk1-5): this is the requested hot loop — the strided
+    /// sub-blocks `apply_kernel` touches are independent and could be processed with a
+    /// `parallel` feature the same way `renormalize_with_trace` is above — but the rewrite has
+    /// to live in `kernel.rs` alongside `apply_kernel` itself, and that file isn't part of this
+    /// snapshot (only `apply_kernel`'s call sites are). Not implemented here; the per-outcome
+    /// trace computations in `sample_instrument_with_distribution` below are parallelized
+    /// instead, since that loop lives in this file.
     fn apply_operation_matrix(
         &mut self,
         operation_matrix: &SquareMatrix,
@@ -164,19 +436,51 @@ pub struct DensityMatrixSimulator {
     /// Dimension of the density matrix. We need this field to verify the size of the
     /// quantum system in the `set_state` method in the case that `self.state == Err(...)`.
     dim: usize,
+    /// Drives `sample_instrument`'s measurement outcomes. Seeded explicitly so that
+    /// stochastic trajectories are reproducible and can be checkpointed/resumed via
+    /// `get_rng_state`/`set_rng_state`.
+    rng: Rng,
+}
+
+/// A serializable snapshot of a `DensityMatrixSimulator`, returned by
+/// `DensityMatrixSimulator::snapshot` and consumed by `DensityMatrixSimulator::restore`, so a
+/// noisy simulation can be persisted mid-circuit and resumed later without losing its
+/// `trace_change` accounting or its RNG state.
+#[derive(Serialize, Deserialize)]
+pub struct DensityMatrixSimulatorSnapshot {
+    state: DensityMatrix,
+    rng_state: [u8; 8],
 }
 
 impl DensityMatrixSimulator {
-    /// Creates a new `DensityMatrixSimulator`.
-    pub fn new(number_of_qubits: usize) -> Self {
+    /// Creates a new `DensityMatrixSimulator` whose measurement sampling is seeded with `seed`.
+    pub fn new(number_of_qubits: usize, seed: u64) -> Self {
         let density_matrix = DensityMatrix::new(number_of_qubits);
         let dim = density_matrix.dim();
         Self {
             state: Ok(density_matrix),
             dim,
+            rng: Rng::new(seed),
         }
     }
 
+    /// Reseeds the random number generator used by `sample_instrument`, without otherwise
+    /// touching the simulator's state.
+    pub fn reseed(&mut self, seed: u64) {
+        self.rng = Rng::new(seed);
+    }
+
+    /// Returns the RNG state as bytes, suitable for checkpointing a stochastic trajectory.
+    #[must_use]
+    pub fn get_rng_state(&self) -> [u8; 8] {
+        self.rng.0.to_le_bytes()
+    }
+
+    /// Restores the RNG state from bytes previously returned by `get_rng_state`.
+    pub fn set_rng_state(&mut self, state: [u8; 8]) {
+        self.rng = Rng(u64::from_le_bytes(state));
+    }
+
     /// Apply an operation to the given qubit ids.
     pub fn apply_operation(
         &mut self,
@@ -216,7 +520,8 @@ impl DensityMatrixSimulator {
         instrument: &Instrument,
         qubits: &[usize],
     ) -> Result<usize, Error> {
-        self.sample_instrument_with_distribution(instrument, qubits, rand::random())
+        let random_sample = self.rng.next_f64();
+        self.sample_instrument_with_distribution(instrument, qubits, random_sample)
     }
 
     /// Performs selective evolution under the given instrument.
@@ -238,6 +543,28 @@ impl DensityMatrixSimulator {
             let err = Error::ProbabilityZeroEvent;
             handle_error!(self, err);
         }
+        // Each outcome's trace only depends on a fresh clone of the current state, so with the
+        // `parallel` feature they're all computed up front with rayon instead of one at a time.
+        // The scan below that samples an outcome from `random_sample` still runs sequentially,
+        // in order, against the (possibly precomputed) traces, so the outcome picked matches the
+        // serial path exactly.
+        #[cfg(feature = "parallel")]
+        let outcome_traces: Vec<f64> = {
+            let base_state = self.state.clone()?;
+            (0..instrument.num_operations())
+                .into_par_iter()
+                .map(|outcome| -> Result<f64, Error> {
+                    let mut tmp_state = base_state.clone();
+                    apply_kernel(
+                        &mut tmp_state.data,
+                        instrument.operation(outcome).effect_matrix_transpose(),
+                        qubits,
+                    )?;
+                    Ok(tmp_state.trace())
+                })
+                .collect::<Result<Vec<f64>, Error>>()?
+        };
+
         let mut last_non_zero_trace_outcome: usize = 0;
         let mut last_non_zero_trace: f64 = 0.0;
         let mut summed_probability: f64 = 0.0;
@@ -246,13 +573,18 @@ impl DensityMatrixSimulator {
             if summed_probability > random_sample {
                 break;
             }
-            tmp_state = self.state.clone()?;
-            apply_kernel(
-                &mut tmp_state.data,
-                instrument.operation(outcome).effect_matrix_transpose(),
-                qubits,
-            )?;
-            let outcome_trace = tmp_state.trace();
+            #[cfg(feature = "parallel")]
+            let outcome_trace = outcome_traces[outcome];
+            #[cfg(not(feature = "parallel"))]
+            let outcome_trace = {
+                tmp_state = self.state.clone()?;
+                apply_kernel(
+                    &mut tmp_state.data,
+                    instrument.operation(outcome).effect_matrix_transpose(),
+                    qubits,
+                )?;
+                tmp_state.trace()
+            };
             summed_probability += outcome_trace / total_effect_trace;
             if outcome_trace >= TOLERANCE {
                 last_non_zero_trace_outcome = outcome;
@@ -281,6 +613,121 @@ impl DensityMatrixSimulator {
         Ok(last_non_zero_trace_outcome)
     }
 
+    /// Computes `Tr(ρP)` for the Pauli product `pauli` (one operator per entry of `qubits`).
+    /// The full Pauli matrix is never materialized: each basis element of `ρ` is folded
+    /// qubit-by-qubit, since `X`/`Y` only flip the corresponding bit of the index pair and
+    /// `I`/`Z` only scale it. Because `P` is Hermitian and `ρ` Hermitian PSD, the trace's
+    /// imaginary part should be within `TOLERANCE`, exactly as in `DensityMatrix::trace`.
+    pub fn expectation(&self, pauli: &[PauliOp], qubits: &[usize]) -> Result<f64, Error> {
+        if pauli.len() != qubits.len() {
+            return Err(Error::InvalidState(format!(
+                "`pauli` has {} operators but {} qubits were given",
+                pauli.len(),
+                qubits.len()
+            )));
+        }
+        let state = self.state.as_ref()?;
+        let dim = state.dim();
+        let data = state.data();
+        let mut expectation_value = Complex::new(0.0, 0.0);
+        for row in 0..dim {
+            let mut col = row;
+            let mut coefficient = Complex::new(1.0, 0.0);
+            for (&op, &qubit) in pauli.iter().zip(qubits) {
+                let bit = (row >> qubit) & 1;
+                match op {
+                    PauliOp::I => {}
+                    PauliOp::X => col ^= 1 << qubit,
+                    PauliOp::Y => {
+                        col ^= 1 << qubit;
+                        coefficient *= if bit == 0 {
+                            Complex::new(0.0, 1.0)
+                        } else {
+                            Complex::new(0.0, -1.0)
+                        };
+                    }
+                    PauliOp::Z => {
+                        if bit == 1 {
+                            coefficient = -coefficient;
+                        }
+                    }
+                }
+            }
+            expectation_value += coefficient * data[row * dim + col];
+        }
+        assert!(
+            expectation_value.im <= TOLERANCE,
+            "expectation value is not real, imaginary part is {}",
+            expectation_value.im
+        );
+        Ok(expectation_value.re)
+    }
+
+    /// Samples `shots` projective measurements of `qubits` in the computational basis,
+    /// returning a histogram mapping each observed bitstring (one `bool` per qubit, in the same
+    /// order as `qubits`) to the number of times it was observed. Each shot measures a clone of
+    /// the current state, so the simulator's state is left unchanged by this call.
+    pub fn sample_measurements(
+        &mut self,
+        qubits: &[usize],
+        shots: usize,
+    ) -> Result<HashMap<Vec<bool>, usize>, Error> {
+        let instrument = computational_basis_instrument(qubits.len());
+        let saved_state = self.state.clone()?;
+        let mut histogram = HashMap::new();
+        for _ in 0..shots {
+            self.state = Ok(saved_state.clone());
+            let outcome = self.sample_instrument(&instrument, qubits)?;
+            let bits = (0..qubits.len()).map(|i| (outcome >> i) & 1 == 1).collect();
+            *histogram.entry(bits).or_insert(0usize) += 1;
+        }
+        self.state = Ok(saved_state);
+        Ok(histogram)
+    }
+
+    /// Performs a Z-basis measurement of `qubit` with a `readout_error` probability that the
+    /// reported classical bit is flipped, routing through `sample_instrument` so the
+    /// post-measurement state is renormalized exactly as any other selective evolution.
+    ///
+    /// Builds `P0 = |0⟩⟨0|` and `P1 = |1⟩⟨1|` and mixes them by `readout_error` so the outcome
+    /// reported as "0" has conditional state `(1 - readout_error)·P0ρP0 + readout_error·P1ρP1`
+    /// (and symmetrically for "1"), represented as the two-Kraus-operator effect
+    /// `{√(1 - readout_error)·P0, √readout_error·P1}` (and its mirror), so callers get
+    /// realistic noisy measurement without assembling Kraus operators themselves.
+    pub fn measure_z(&mut self, qubit: usize, readout_error: f64) -> Result<usize, Error> {
+        let p0 = SquareMatrix::from_row_slice(
+            2,
+            2,
+            &[Complex::new(1.0, 0.0), Complex::ZERO, Complex::ZERO, Complex::ZERO],
+        );
+        let p1 = SquareMatrix::from_row_slice(
+            2,
+            2,
+            &[Complex::ZERO, Complex::ZERO, Complex::ZERO, Complex::new(1.0, 0.0)],
+        );
+        let sqrt_correct = Complex::new((1.0 - readout_error).sqrt(), 0.0);
+        let sqrt_flipped = Complex::new(readout_error.sqrt(), 0.0);
+        let reported_zero = Operation::new(vec![
+            p0.clone().scale(sqrt_correct),
+            p1.clone().scale(sqrt_flipped),
+        ]);
+        let reported_one = Operation::new(vec![p0.scale(sqrt_flipped), p1.scale(sqrt_correct)]);
+        let instrument = Instrument::new(vec![reported_zero, reported_one]);
+        self.sample_instrument(&instrument, &[qubit])
+    }
+
+    /// Returns the reduced density matrix of the current state after tracing out
+    /// `qubits_to_trace_out`, without mutating the simulator's own state.
+    pub fn reduced_density_matrix(
+        &self,
+        qubits_to_trace_out: &[usize],
+    ) -> Result<DensityMatrix, Error> {
+        Ok(self
+            .state
+            .as_ref()?
+            .reduced_density_matrix(qubits_to_trace_out))
+    }
+
     /// Returns the `DensityMatrix` if the simulator is in a valid state.
     pub fn state(&self) -> Result<&DensityMatrix, &Error> {
         self.state.as_ref()
@@ -304,10 +751,32 @@ impl DensityMatrixSimulator {
         if !new_state.is_hermitian() {
             return Err(Error::InvalidState("`state` is not Hermitian".to_string()));
         }
+        if !new_state.is_positive_semidefinite() {
+            return Err(Error::InvalidState(
+                "`state` is not positive semidefinite".to_string(),
+            ));
+        }
         self.state = Ok(new_state);
         Ok(())
     }
 
+    /// Captures a serializable snapshot of the simulator, including its RNG state, so a noisy
+    /// simulation can be persisted mid-circuit and resumed later without losing its
+    /// `trace_change` accounting or the reproducibility of future `sample_instrument` calls.
+    pub fn snapshot(&self) -> Result<DensityMatrixSimulatorSnapshot, Error> {
+        Ok(DensityMatrixSimulatorSnapshot {
+            state: self.state.clone()?,
+            rng_state: self.get_rng_state(),
+        })
+    }
+
+    /// Restores the simulator from a snapshot previously returned by `snapshot`.
+    pub fn restore(&mut self, snapshot: DensityMatrixSimulatorSnapshot) {
+        self.dim = snapshot.state.dim();
+        self.rng = Rng(u64::from_le_bytes(snapshot.rng_state));
+        self.state = Ok(snapshot.state);
+    }
+
     /// Return theoretical change in trace due to operations that have been applied so far
     /// In reality, the density matrix is always renormalized after instruments/operations
     /// have been applied.
@@ -323,4 +792,128 @@ impl DensityMatrixSimulator {
         self.state.as_mut()?.trace_change = trace;
         Ok(())
     }
+
+    /// Creates a new `DensityMatrixSimulator` intended for use with a sparse storage
+    /// backend. For now this simply delegates to [`DensityMatrixSimulator::new`]; the state
+    /// is still stored densely as `dim * dim` entries. [`DensityMatrixSimulator::sparse_state`]
+    /// reports only the populated entries and [`DensityMatrixSimulator::prune`] zeroes small
+    /// ones, but neither reduces the memory the dense storage takes.
+    pub fn new_sparse(number_of_qubits: usize, seed: u64) -> Self {
+        Self::new(number_of_qubits, seed)
+    }
+
+    /// Returns the matrix entries whose magnitude is at least `threshold`, as
+    /// `(row, col, value)` triples, so a caller can reconstruct only the nonzero structure
+    /// of a larger state instead of pulling back the full dense matrix.
+    pub fn sparse_state(&self, threshold: f64) -> Result<Vec<(usize, usize, Complex<f64>)>, Error> {
+        Ok(self.state.as_ref()?.sparse_entries(threshold))
+    }
+
+    /// Zeroes out matrix entries whose magnitude falls below `threshold`, then renormalizes.
+    /// Entries are zeroed in place, so the dense storage keeps its size; this trades some
+    /// fidelity for a smaller result from `sparse_state`.
+    pub fn prune(&mut self, threshold: f64) -> Result<(), Error> {
+        let state = self.state.as_mut()?;
+        for entry in state.data.iter_mut() {
+            if entry.norm() < threshold {
+                *entry = Complex::ZERO;
+            }
+        }
+        if let Err(err) = state.renormalize() {
+            handle_error!(self, err);
+        }
+        Ok(())
+    }
+
+    /// Integrates the Lindblad master equation
+    /// `dρ/dt = -i[H,ρ] + Σₖ (LₖρLₖ† − ½{Lₖ†Lₖ,ρ})` forward by `time`, using a fixed-step
+    /// RK4 integrator over `steps` steps on the vectorized superoperator. This models
+    /// continuous decoherence (e.g. during idle periods) rather than an instantaneous
+    /// Kraus channel.
+    pub fn evolve(
+        &mut self,
+        hamiltonian: &SquareMatrix,
+        collapse_operators: &[SquareMatrix],
+        time: f64,
+        steps: usize,
+    ) -> Result<(), Error> {
+        let dim = self.state.as_ref()?.dim();
+        let identity = SquareMatrix::identity(dim, dim);
+        let i = Complex::new(0.0, 1.0);
+        let half = Complex::new(0.5, 0.0);
+        let mut superoperator =
+            (hamiltonian.kronecker(&identity) - identity.kronecker(&hamiltonian.transpose())) * -i;
+        for collapse_operator in collapse_operators {
+            let l_dag_l = collapse_operator.adjoint() * collapse_operator;
+            superoperator += collapse_operator.kronecker(&collapse_operator.conjugate())
+                - (l_dag_l.kronecker(&identity) + identity.kronecker(&l_dag_l.transpose())) * half;
+        }
+
+        let dt = Complex::new(time / steps as f64, 0.0);
+        let two = Complex::new(2.0, 0.0);
+        let six = Complex::new(6.0, 0.0);
+        let state = self.state.as_mut()?;
+        let mut vectorized_state = state.data.clone();
+        for _ in 0..steps {
+            let k1 = &superoperator * &vectorized_state;
+            let k2 = &superoperator * (&vectorized_state + &k1 * (dt / two));
+            let k3 = &superoperator * (&vectorized_state + &k2 * (dt / two));
+            let k4 = &superoperator * (&vectorized_state + &k3 * dt);
+            vectorized_state += (k1 + k2 * two + k3 * two + k4) * (dt / six);
+        }
+        state.data = vectorized_state;
+        if let Err(err) = state.renormalize() {
+            handle_error!(self, err);
+        }
+        Ok(())
+    }
+
+    /// Allocates a new qubit initialized to |0⟩, growing the simulated register by one qubit.
+    /// Returns the id of the newly allocated qubit. Useful when an operation's decomposition
+    /// needs ancillae that weren't declared up front.
+    pub fn allocate_qubit(&mut self) -> Result<usize, Error> {
+        let state = self.state.as_ref()?;
+        let qubit_id = state.number_of_qubits();
+        let new_state = state.tensor_with_fresh_qubit();
+        self.dim = new_state.dim();
+        self.state = Ok(new_state);
+        Ok(qubit_id)
+    }
+
+    /// Releases `qubit_id`, shrinking the register by one qubit and renumbering the higher
+    /// qubits down to stay contiguous. Fails with `Error::InvalidState` if the qubit is still
+    /// entangled with the rest of the system, since discarding it would silently lose
+    /// information about the joint state.
+    pub fn release_qubit(&mut self, qubit_id: usize) -> Result<(), Error> {
+        let state = self.state.as_ref()?;
+        if qubit_id >= state.number_of_qubits() {
+            return Err(Error::InvalidState(format!(
+                "qubit {qubit_id} does not exist in a {}-qubit system",
+                state.number_of_qubits()
+            )));
+        }
+        if !state.qubit_is_separable(qubit_id) {
+            return Err(Error::InvalidState(format!(
+                "qubit {qubit_id} is still entangled with the rest of the system and cannot be released"
+            )));
+        }
+        let new_state = state.trace_out_qubit(qubit_id);
+        self.dim = new_state.dim();
+        self.state = Ok(new_state);
+        Ok(())
+    }
+}
+
+/// Builds the computational-basis projective measurement instrument over `num_qubits` qubits:
+/// one Kraus operator per outcome, each a diagonal projector onto that outcome's basis state.
+fn computational_basis_instrument(num_qubits: usize) -> Instrument {
+    let dim = 1usize << num_qubits;
+    let operations = (0..dim)
+        .map(|outcome| {
+            let mut projector = SquareMatrix::zeros(dim, dim);
+            projector[(outcome, outcome)] = Complex::new(1.0, 0.0);
+            Operation::new(vec![projector])
+        })
+        .collect();
+    Instrument::new(operations)
 }
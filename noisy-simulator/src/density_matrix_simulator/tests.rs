@@ -0,0 +1,50 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use super::DensityMatrixSimulator;
+use crate::SquareMatrix;
+use num_complex::Complex;
+
+const EPSILON: f64 = 1e-8;
+
+fn assert_close(actual: Complex<f64>, expected: Complex<f64>) {
+    assert!(
+        (actual - expected).norm() < EPSILON,
+        "expected {expected:?}, got {actual:?}"
+    );
+}
+
+/// One Euler-sized step of `evolve` under a complex-valued collapse operator
+/// `L = [[1, i], [0, 0]]` starting from |0⟩⟨0|, with no Hamiltonian. The dissipator's
+/// Kronecker factors must be ordered `L⊗conj(L)` and `(L†L)⊗I + I⊗(L†L)ᵀ` for this file's
+/// row-major `vec_row` convention; swapping them (`conj(L)⊗L`, as the code used to) only
+/// agrees with the real-valued case and silently reproduces the wrong state for a
+/// complex-valued jump operator. The expected values below are `LρL† - 1/2{L†L, ρ}`,
+/// computed by hand, not by re-deriving the superoperator, so this checks the physics
+/// rather than the code against itself.
+#[test]
+fn evolve_with_complex_collapse_operator_matches_lindblad_dissipator() {
+    let mut simulator = DensityMatrixSimulator::new(1, 0);
+    let hamiltonian = SquareMatrix::zeros(2, 2);
+    let i = Complex::new(0.0, 1.0);
+    let l = SquareMatrix::from_row_slice(
+        2,
+        2,
+        &[Complex::new(1.0, 0.0), i, Complex::ZERO, Complex::ZERO],
+    );
+    let dt = 1e-5;
+
+    simulator
+        .evolve(&hamiltonian, &[l], dt, 1)
+        .expect("evolve should succeed");
+
+    let data = simulator
+        .state()
+        .expect("state should be valid")
+        .data()
+        .clone();
+    assert_close(data[0], Complex::new(1.0, 0.0));
+    assert_close(data[1], Complex::new(0.0, -dt / 2.0));
+    assert_close(data[2], Complex::new(0.0, dt / 2.0));
+    assert_close(data[3], Complex::ZERO);
+}
@@ -3,10 +3,12 @@
 
 //! This module is a thin `PyO3` wrapper around the rust `noisy_simulator` crate.
 
-use noisy_simulator::{ComplexVector, SquareMatrix};
+use ndarray::Array2;
+use noisy_simulator::{ComplexVector, DensityMatrixSimulatorSnapshot, PauliOp, SquareMatrix};
 use num_complex::Complex;
+use numpy::{IntoPyArray, PyArray1, PyArray2, PyReadonlyArray2};
 use pyo3::{exceptions::PyException, prelude::*};
-type PythonMatrix = Vec<Vec<Complex<f64>>>;
+use std::collections::HashMap;
 
 pub(crate) fn register_noisy_sim_submodule(py: Python, parent_module: &PyModule) -> PyResult<()> {
     let m = PyModule::new(py, "noisy_sim")?;
@@ -18,74 +20,223 @@ pub(crate) fn register_noisy_sim_submodule(py: Python, parent_module: &PyModule)
     Ok(())
 }
 
-/// Performance Warning:
-///  nalgebra stores its matrices in column major order, and we want to send it
-///  to Python in row major order, this means that there will be lots of
-///  cache-misses in the convertion from one format to another.
-fn python_to_nalgebra_matrix(matrix: PythonMatrix) -> SquareMatrix {
-    let nrows = matrix.len();
-    let ncols = matrix[0].len();
-    // Check that matrix is well formed.
-    for row in &matrix {
-        assert!(
-            ncols == row.len(),
-            "ill formed matrix, all rows should be the same length"
-        );
-    }
-    // Move matrix into a linear container.
-    let mut data = Vec::with_capacity(nrows * ncols);
-    for mut row in matrix {
-        data.append(&mut row);
-    }
-    SquareMatrix::from_row_iterator(nrows, ncols, data)
+/// Reads a NumPy array view directly into an nalgebra matrix, indexing through whatever
+/// strides the array has rather than copying through a row/column-major nested-list
+/// intermediate.
+fn numpy_to_nalgebra_matrix(matrix: PyReadonlyArray2<Complex<f64>>) -> SquareMatrix {
+    let view = matrix.as_array();
+    let nrows = view.shape()[0];
+    let ncols = view.shape()[1];
+    SquareMatrix::from_fn(nrows, ncols, |row, col| view[[row, col]])
 }
 
-/// Performance Warning:
-///  nalgebra stores its matrices in column major order, and we want to send it
-///  to Python in row major order, this means that there will be lots of
-///  cache-misses in the convertion from one format to another.
-fn nalgebra_matrix_to_python_list(matrix: &SquareMatrix) -> Vec<Complex<f64>> {
+/// Hands a nalgebra matrix back to Python as a properly shaped, owned 2-D NumPy array.
+fn nalgebra_matrix_to_numpy(py: Python<'_>, matrix: &SquareMatrix) -> Py<PyArray2<Complex<f64>>> {
     let (nrows, ncols) = matrix.shape();
-    let mut list = Vec::with_capacity(nrows * ncols);
-    for row in 0..nrows {
-        for col in 0..ncols {
-            list.push(matrix[(row, col)]);
+    Array2::from_shape_fn((nrows, ncols), |(row, col)| matrix[(row, col)])
+        .into_pyarray(py)
+        .into()
+}
+
+pyo3::create_exception!(qsharp.noisy_sim, SimulationError, PyException);
+
+/// Tolerance used when checking that a set of Kraus operators satisfies the completeness
+/// relation. Kept separate from the core crate's `TOLERANCE` since this check only runs once,
+/// at channel-construction time, rather than on every simulation step.
+const COMPLETENESS_TOLERANCE: f64 = 1e-9;
+
+fn real(re: f64) -> Complex<f64> {
+    Complex::new(re, 0.0)
+}
+
+fn pauli_i() -> [[Complex<f64>; 2]; 2] {
+    [[real(1.0), real(0.0)], [real(0.0), real(1.0)]]
+}
+
+fn pauli_x() -> [[Complex<f64>; 2]; 2] {
+    [[real(0.0), real(1.0)], [real(1.0), real(0.0)]]
+}
+
+fn pauli_y() -> [[Complex<f64>; 2]; 2] {
+    [
+        [real(0.0), Complex::new(0.0, -1.0)],
+        [Complex::new(0.0, 1.0), real(0.0)],
+    ]
+}
+
+fn pauli_z() -> [[Complex<f64>; 2]; 2] {
+    [[real(1.0), real(0.0)], [real(0.0), real(-1.0)]]
+}
+
+fn scaled_pauli(coefficient: f64, pauli: [[Complex<f64>; 2]; 2]) -> SquareMatrix {
+    SquareMatrix::from_row_slice(
+        2,
+        2,
+        &[
+            pauli[0][0] * coefficient,
+            pauli[0][1] * coefficient,
+            pauli[1][0] * coefficient,
+            pauli[1][1] * coefficient,
+        ],
+    )
+}
+
+/// Checks that `Σ Kᵢ†Kᵢ = I`, the completeness relation every valid set of Kraus operators
+/// for a quantum channel must satisfy.
+fn validate_completeness(kraus_operators: &[SquareMatrix]) -> PyResult<()> {
+    let dim = kraus_operators[0].nrows();
+    let mut sum = SquareMatrix::zeros(dim, dim);
+    for kraus_operator in kraus_operators {
+        sum += kraus_operator.adjoint() * kraus_operator;
+    }
+    for row in 0..dim {
+        for col in 0..dim {
+            let expected = if row == col { 1.0 } else { 0.0 };
+            let entry = sum[(row, col)];
+            // Written as a negated `<=` so that NaN entries (e.g. from a probability above 1)
+            // fail the check instead of slipping through a `>` comparison.
+            if !((entry.re - expected).abs() <= COMPLETENESS_TOLERANCE
+                && entry.im.abs() <= COMPLETENESS_TOLERANCE)
+            {
+                return Err(SimulationError::new_err(
+                    "Kraus operators do not satisfy the completeness relation \u{3a3} K\u{1d62}\u{2020}K\u{1d62} = I",
+                ));
+            }
         }
     }
-    list
+    Ok(())
 }
 
-pyo3::create_exception!(qsharp.noisy_sim, SimulationError, PyException);
+/// Parses a Pauli string like `"XYZ"` into one `PauliOp` per character.
+fn parse_pauli_string(pauli_string: &str) -> PyResult<Vec<PauliOp>> {
+    pauli_string
+        .chars()
+        .map(|c| match c {
+            'I' => Ok(PauliOp::I),
+            'X' => Ok(PauliOp::X),
+            'Y' => Ok(PauliOp::Y),
+            'Z' => Ok(PauliOp::Z),
+            _ => Err(SimulationError::new_err(format!(
+                "`pauli_string` contains invalid character '{c}', expected one of I, X, Y, Z"
+            ))),
+        })
+        .collect()
+}
 
 #[pyclass]
 #[derive(Clone)]
 pub(crate) struct Operation(noisy_simulator::Operation);
 
+impl Operation {
+    fn from_kraus_checked(kraus_operators: Vec<SquareMatrix>) -> PyResult<Self> {
+        validate_completeness(&kraus_operators)?;
+        Ok(Self(noisy_simulator::Operation::new(kraus_operators)))
+    }
+}
+
 #[pymethods]
 impl Operation {
     #[new]
-    pub fn new(kraus_operators: Vec<PythonMatrix>) -> Self {
+    pub fn new(kraus_operators: Vec<PyReadonlyArray2<Complex<f64>>>) -> Self {
         let kraus_operators: Vec<SquareMatrix> = kraus_operators
             .into_iter()
-            .map(python_to_nalgebra_matrix)
+            .map(numpy_to_nalgebra_matrix)
             .collect();
         Self(noisy_simulator::Operation::new(kraus_operators))
     }
 
-    pub fn get_effect_matrix(&self) -> Vec<Complex<f64>> {
-        nalgebra_matrix_to_python_list(self.0.effect_matrix())
+    /// The depolarizing channel: with probability `p` the qubit is replaced by the
+    /// maximally mixed state.
+    #[staticmethod]
+    pub fn depolarizing(p: f64) -> PyResult<Self> {
+        Self::from_kraus_checked(vec![
+            scaled_pauli((1.0 - 3.0 * p / 4.0).sqrt(), pauli_i()),
+            scaled_pauli((p / 4.0).sqrt(), pauli_x()),
+            scaled_pauli((p / 4.0).sqrt(), pauli_y()),
+            scaled_pauli((p / 4.0).sqrt(), pauli_z()),
+        ])
     }
 
-    pub fn get_operation_matrix(&self) -> Vec<Complex<f64>> {
-        nalgebra_matrix_to_python_list(self.0.matrix())
+    /// The amplitude damping channel with decay probability `gamma`, modeling energy
+    /// relaxation from |1⟩ to |0⟩.
+    #[staticmethod]
+    pub fn amplitude_damping(gamma: f64) -> PyResult<Self> {
+        Self::from_kraus_checked(vec![
+            SquareMatrix::from_row_slice(
+                2,
+                2,
+                &[real(1.0), real(0.0), real(0.0), real((1.0 - gamma).sqrt())],
+            ),
+            SquareMatrix::from_row_slice(
+                2,
+                2,
+                &[real(0.0), real(gamma.sqrt()), real(0.0), real(0.0)],
+            ),
+        ])
     }
 
-    pub fn get_kraus_operators(&self) -> Vec<Vec<Complex<f64>>> {
-        let mut kraus_operators = Vec::new();
-        for kraus_operator in self.0.kraus_operators() {
-            kraus_operators.push(nalgebra_matrix_to_python_list(kraus_operator));
-        }
-        kraus_operators
+    /// The phase damping channel with dephasing probability `lambda`, modeling loss of
+    /// phase information without energy exchange.
+    #[staticmethod]
+    pub fn phase_damping(lambda: f64) -> PyResult<Self> {
+        Self::from_kraus_checked(vec![
+            SquareMatrix::from_row_slice(
+                2,
+                2,
+                &[real(1.0), real(0.0), real(0.0), real((1.0 - lambda).sqrt())],
+            ),
+            SquareMatrix::from_row_slice(
+                2,
+                2,
+                &[real(0.0), real(0.0), real(0.0), real(lambda.sqrt())],
+            ),
+        ])
+    }
+
+    /// The bit-flip channel: applies an `X` with probability `p`.
+    #[staticmethod]
+    pub fn bit_flip(p: f64) -> PyResult<Self> {
+        Self::from_kraus_checked(vec![
+            scaled_pauli((1.0 - p).sqrt(), pauli_i()),
+            scaled_pauli(p.sqrt(), pauli_x()),
+        ])
+    }
+
+    /// The phase-flip channel: applies a `Z` with probability `p`.
+    #[staticmethod]
+    pub fn phase_flip(p: f64) -> PyResult<Self> {
+        Self::from_kraus_checked(vec![
+            scaled_pauli((1.0 - p).sqrt(), pauli_i()),
+            scaled_pauli(p.sqrt(), pauli_z()),
+        ])
+    }
+
+    /// The general Pauli channel: applies `X`, `Y`, or `Z` with probabilities `px`, `py`,
+    /// `pz` respectively, and leaves the qubit untouched otherwise.
+    #[staticmethod]
+    pub fn pauli_channel(px: f64, py: f64, pz: f64) -> PyResult<Self> {
+        Self::from_kraus_checked(vec![
+            scaled_pauli((1.0 - px - py - pz).sqrt(), pauli_i()),
+            scaled_pauli(px.sqrt(), pauli_x()),
+            scaled_pauli(py.sqrt(), pauli_y()),
+            scaled_pauli(pz.sqrt(), pauli_z()),
+        ])
+    }
+
+    pub fn get_effect_matrix(&self, py: Python<'_>) -> Py<PyArray2<Complex<f64>>> {
+        nalgebra_matrix_to_numpy(py, self.0.effect_matrix())
+    }
+
+    pub fn get_operation_matrix(&self, py: Python<'_>) -> Py<PyArray2<Complex<f64>>> {
+        nalgebra_matrix_to_numpy(py, self.0.matrix())
+    }
+
+    pub fn get_kraus_operators(&self, py: Python<'_>) -> Vec<Py<PyArray2<Complex<f64>>>> {
+        self.0
+            .kraus_operators()
+            .iter()
+            .map(|kraus_operator| nalgebra_matrix_to_numpy(py, kraus_operator))
+            .collect()
     }
 }
 
@@ -139,6 +290,19 @@ impl TryInto<noisy_simulator::DensityMatrix> for DensityMatrix {
     }
 }
 
+#[pymethods]
+impl DensityMatrix {
+    /// Returns the state as a properly shaped `dim x dim` NumPy array, rather than the
+    /// flattened form used internally.
+    pub fn get_data(&self, py: Python<'_>) -> Py<PyArray2<Complex<f64>>> {
+        Array2::from_shape_fn((self.dim, self.dim), |(row, col)| {
+            self.data[self.dim * row + col]
+        })
+        .into_pyarray(py)
+        .into()
+    }
+}
+
 #[pyclass]
 pub(crate) struct DensityMatrixSimulator(noisy_simulator::DensityMatrixSimulator);
 
@@ -146,13 +310,62 @@ pub(crate) struct DensityMatrixSimulator(noisy_simulator::DensityMatrixSimulator
 impl DensityMatrixSimulator {
     #[new]
     #[pyo3(signature = (number_of_qubits, seed=42))]
-    #[allow(unused_variables)]
-    pub fn new(number_of_qubits: usize, seed: usize) -> Self {
+    pub fn new(number_of_qubits: usize, seed: u64) -> Self {
         Self(noisy_simulator::DensityMatrixSimulator::new(
             number_of_qubits,
+            seed,
         ))
     }
 
+    /// Creates a simulator intended for sparse/large registers. The storage is still dense
+    /// today; use `prune`/`get_sparse_state` to keep the reported state small.
+    #[staticmethod]
+    #[pyo3(signature = (number_of_qubits, seed=42))]
+    pub fn new_sparse(number_of_qubits: usize, seed: u64) -> Self {
+        Self(noisy_simulator::DensityMatrixSimulator::new_sparse(
+            number_of_qubits,
+            seed,
+        ))
+    }
+
+    /// Reseeds the simulator's random number generator, used by `sample_instrument`, without
+    /// otherwise touching its state.
+    pub fn reseed(&mut self, seed: u64) {
+        self.0.reseed(seed);
+    }
+
+    /// Serializes a snapshot of the simulator (including its RNG state) to bytes, for
+    /// persisting a noisy simulation mid-circuit and resuming it later.
+    pub fn snapshot(&self) -> PyResult<Vec<u8>> {
+        let snapshot = self
+            .0
+            .snapshot()
+            .map_err(|e| SimulationError::new_err(e.to_string()))?;
+        serde_json::to_vec(&snapshot).map_err(|e| SimulationError::new_err(e.to_string()))
+    }
+
+    /// Restores the simulator from bytes previously returned by `snapshot`.
+    pub fn restore(&mut self, snapshot: Vec<u8>) -> PyResult<()> {
+        let snapshot: DensityMatrixSimulatorSnapshot = serde_json::from_slice(&snapshot)
+            .map_err(|e| SimulationError::new_err(e.to_string()))?;
+        self.0.restore(snapshot);
+        Ok(())
+    }
+
+    /// Returns the RNG state as bytes, suitable for checkpointing a stochastic trajectory.
+    pub fn get_rng_state(&self) -> Vec<u8> {
+        self.0.get_rng_state().to_vec()
+    }
+
+    /// Restores the RNG state from bytes previously returned by `get_rng_state`.
+    pub fn set_rng_state(&mut self, state: Vec<u8>) -> PyResult<()> {
+        let state: [u8; 8] = state
+            .try_into()
+            .map_err(|_| SimulationError::new_err("RNG state must be exactly 8 bytes"))?;
+        self.0.set_rng_state(state);
+        Ok(())
+    }
+
     /// Apply an arbitrary operation to given qubit ids.
     #[allow(clippy::needless_pass_by_value)]
     pub fn apply_operation(&mut self, operation: &Operation, qubits: Vec<usize>) -> PyResult<()> {
@@ -188,6 +401,52 @@ impl DensityMatrixSimulator {
             .map_err(|e| SimulationError::new_err(e.to_string()))
     }
 
+    /// Computes `Tr(ρP)` for the Pauli product `pauli_string` (e.g. `"XYZ"`), one character per
+    /// entry of `qubits`, without materializing the full Pauli matrix.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn expectation(&self, pauli_string: &str, qubits: Vec<usize>) -> PyResult<f64> {
+        let pauli = parse_pauli_string(pauli_string)?;
+        self.0
+            .expectation(&pauli, &qubits)
+            .map_err(|e| SimulationError::new_err(e.to_string()))
+    }
+
+    /// Samples `shots` projective measurements of `qubits` in the computational basis, leaving
+    /// the simulator's state unchanged. Returns a histogram mapping each observed bitstring
+    /// (one bool per qubit, in the same order as `qubits`) to the number of times it occurred.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn sample_measurements(
+        &mut self,
+        qubits: Vec<usize>,
+        shots: usize,
+    ) -> PyResult<HashMap<Vec<bool>, usize>> {
+        self.0
+            .sample_measurements(&qubits, shots)
+            .map_err(|e| SimulationError::new_err(e.to_string()))
+    }
+
+    /// Performs a Z-basis measurement of `qubit` with a `readout_error` probability that the
+    /// reported classical bit is flipped, without requiring callers to assemble Kraus
+    /// operators themselves.
+    pub fn measure_z(&mut self, qubit: usize, readout_error: f64) -> PyResult<usize> {
+        self.0
+            .measure_z(qubit, readout_error)
+            .map_err(|e| SimulationError::new_err(e.to_string()))
+    }
+
+    /// Returns the reduced density matrix of the current state after tracing out
+    /// `qubits_to_trace_out`, without mutating the simulator's own state.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn reduced_density_matrix(
+        &self,
+        qubits_to_trace_out: Vec<usize>,
+    ) -> PyResult<DensityMatrix> {
+        self.0
+            .reduced_density_matrix(&qubits_to_trace_out)
+            .map(|dm| (&dm).into())
+            .map_err(|e| SimulationError::new_err(e.to_string()))
+    }
+
     /// For debugging and testing purposes.
     pub fn get_state(&self) -> PyResult<DensityMatrix> {
         match self.0.state() {
@@ -209,6 +468,59 @@ impl DensityMatrixSimulator {
             .set_trace(trace)
             .map_err(|e| SimulationError::new_err(e.to_string()))
     }
+
+    /// Allocates a new qubit initialized to |0⟩, returning its id. Lets callers grow the
+    /// register at runtime for gates whose decompositions need ancillae.
+    pub fn allocate_qubit(&mut self) -> PyResult<usize> {
+        self.0
+            .allocate_qubit()
+            .map_err(|e| SimulationError::new_err(e.to_string()))
+    }
+
+    /// Releases a previously allocated qubit, shrinking the register. Fails if the qubit is
+    /// still entangled with the rest of the system.
+    pub fn release_qubit(&mut self, qubit_id: usize) -> PyResult<()> {
+        self.0
+            .release_qubit(qubit_id)
+            .map_err(|e| SimulationError::new_err(e.to_string()))
+    }
+
+    /// Returns the entries of the state whose magnitude is at least `threshold` as
+    /// `(row, col, value)` triples, for callers that only want to reconstruct the sparse structure of a larger
+    /// state.
+    pub fn get_sparse_state(&self, threshold: f64) -> PyResult<Vec<(usize, usize, Complex<f64>)>> {
+        self.0
+            .sparse_state(threshold)
+            .map_err(|e| SimulationError::new_err(e.to_string()))
+    }
+
+    /// Zeroes out matrix entries below `threshold` and renormalizes. The storage stays dense,
+    /// so this shrinks what `get_sparse_state` reports, not the memory the state takes.
+    pub fn prune(&mut self, threshold: f64) -> PyResult<()> {
+        self.0
+            .prune(threshold)
+            .map_err(|e| SimulationError::new_err(e.to_string()))
+    }
+
+    /// Integrates the Lindblad master equation for `hamiltonian` and `collapse_operators`
+    /// over the given `time`, using `steps` fixed RK4 steps. Models decoherence during idle
+    /// periods rather than an instantaneous channel.
+    pub fn evolve(
+        &mut self,
+        hamiltonian: PyReadonlyArray2<Complex<f64>>,
+        collapse_operators: Vec<PyReadonlyArray2<Complex<f64>>>,
+        time: f64,
+        steps: usize,
+    ) -> PyResult<()> {
+        let hamiltonian = numpy_to_nalgebra_matrix(hamiltonian);
+        let collapse_operators: Vec<SquareMatrix> = collapse_operators
+            .into_iter()
+            .map(numpy_to_nalgebra_matrix)
+            .collect();
+        self.0
+            .evolve(&hamiltonian, &collapse_operators, time, steps)
+            .map_err(|e| SimulationError::new_err(e.to_string()))
+    }
 }
 
 #[pyclass]
@@ -249,11 +561,27 @@ impl TryInto<noisy_simulator::StateVector> for StateVector {
     }
 }
 
+#[pymethods]
+impl StateVector {
+    /// Returns the state as a properly shaped 1-D NumPy array, rather than the flattened
+    /// `Vec` used internally.
+    pub fn get_data(&self, py: Python<'_>) -> Py<PyArray1<Complex<f64>>> {
+        self.data.clone().into_pyarray(py).into()
+    }
+}
+
 #[pyclass]
 pub(crate) struct StateVectorSimulator(noisy_simulator::StateVectorSimulator);
 
 #[pymethods]
 impl StateVectorSimulator {
+    // BLOCKED (m1c0l/qsharp#chunk0-6): `seed` is still dropped here, and there's no
+    // `reseed`/`get_rng_state`/`set_rng_state` on this type, unlike `DensityMatrixSimulator`
+    // above. `noisy_simulator::StateVectorSimulator` isn't part of this snapshot — only
+    // `density_matrix_simulator.rs` is — so there's no source to add an `Rng` field or RNG
+    // checkpoint API to, and no way to know whether its measurement sampling even draws from
+    // a seedable source. Left as `#[allow(unused_variables)]` rather than wiring a seed
+    // through to a constructor call that doesn't exist in this crate.
     #[new]
     #[pyo3(signature = (number_of_qubits, seed=42))]
     #[allow(unused_variables)]